@@ -0,0 +1,116 @@
+//! Multinomial Naive Bayes text classifier built on `Tokenizer`.
+
+use std::collections::{HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+
+use crate::tokenizer::Tokenizer;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+/// A multinomial Naive Bayes classifier that reuses `Tokenizer::get_token` for feature
+/// extraction, so classification respects the same stopwords/stemmer/normalization as
+/// indexing.
+pub struct NaiveBayesClassifier {
+    tokenizer: Tokenizer,
+    /// Per-label token counts.
+    token_counts: HashMap<String, HashMap<String, u32>>,
+    /// Per-label total token count, i.e. the sum of `token_counts[label]`.
+    label_token_total: HashMap<String, u32>,
+    /// Per-label document count.
+    label_doc_count: HashMap<String, u32>,
+    /// Vocabulary observed across all labels.
+    vocab: HashSet<String>,
+}
+
+impl NaiveBayesClassifier {
+    /// Create a classifier that extracts features with `tokenizer`.
+    pub fn new(tokenizer: Tokenizer) -> Self {
+        Self {
+            tokenizer,
+            token_counts: HashMap::new(),
+            label_token_total: HashMap::new(),
+            label_doc_count: HashMap::new(),
+            vocab: HashSet::new(),
+        }
+    }
+
+    /// Accumulate token counts for `label` from `text`.
+    pub fn train(&mut self, text: &str, label: &str) {
+        *self.label_doc_count.entry(label.to_string()).or_insert(0) += 1;
+        let counts = self.token_counts.entry(label.to_string()).or_default();
+        let total = self.label_token_total.entry(label.to_string()).or_insert(0);
+        for token in self.tokenizer.get_token(text) {
+            self.vocab.insert(token.clone());
+            *counts.entry(token).or_insert(0) += 1;
+            *total += 1;
+        }
+    }
+
+    /// Log-space, Laplace-smoothed score of `tokens` against `label`.
+    fn label_log_score(&self, label: &str, tokens: &[String]) -> f32 {
+        let doc_count: u32 = self.label_doc_count.values().sum();
+        let prior = self.label_doc_count[label] as f32 / doc_count as f32;
+        let counts = &self.token_counts[label];
+        let total = self.label_token_total[label] as f32;
+        let vocab_len = self.vocab.len() as f32;
+
+        let mut score = prior.ln();
+        for token in tokens {
+            let count = *counts.get(token).unwrap_or(&0) as f32;
+            score += ((count + 1.0) / (total + vocab_len)).ln();
+        }
+        score
+    }
+
+    /// Predict the most likely label for `text`.
+    pub fn predict(&self, text: &str) -> String {
+        let tokens = self.tokenizer.get_token(text);
+        self.label_doc_count
+            .keys()
+            .max_by(|a, b| {
+                self.label_log_score(a, &tokens)
+                    .total_cmp(&self.label_log_score(b, &tokens))
+            })
+            .cloned()
+            .expect("classifier has not been trained on any label")
+    }
+
+    /// Predict normalized class probabilities for `text` via log-sum-exp.
+    pub fn predict_proba(&self, text: &str) -> HashMap<String, f32> {
+        let tokens = self.tokenizer.get_token(text);
+        let scores: HashMap<String, f32> = self
+            .label_doc_count
+            .keys()
+            .map(|label| (label.clone(), self.label_log_score(label, &tokens)))
+            .collect();
+        let max_score = scores.values().cloned().fold(f32::NEG_INFINITY, f32::max);
+        let log_sum_exp = max_score + scores.values().map(|s| (s - max_score).exp()).sum::<f32>().ln();
+        scores
+            .into_iter()
+            .map(|(label, score)| (label, (score - log_sum_exp).exp()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tokenizer::Tokenizer;
+
+    #[test]
+    fn test_predict() {
+        let mut classifier = NaiveBayesClassifier::new(Tokenizer::default());
+        classifier.train("cheap pills buy now act now", "spam");
+        classifier.train("discount pills special offer", "spam");
+        classifier.train("let's meet for lunch tomorrow", "ham");
+        classifier.train("can we reschedule the team meeting", "ham");
+
+        assert_eq!(classifier.predict("buy cheap pills now"), "spam");
+        assert_eq!(classifier.predict("reschedule our lunch meeting"), "ham");
+
+        let proba = classifier.predict_proba("buy cheap pills now");
+        let total: f32 = proba.values().sum();
+        assert!((total - 1.0).abs() < 1e-4);
+        assert!(proba["spam"] > proba["ham"]);
+    }
+}
@@ -0,0 +1,318 @@
+//! Traditional/simplified Han character folding.
+//!
+//! This is a `fast2s`-style lookup table: a flat character-to-character mapping, not a
+//! full phrase-aware conversion. It is large enough to unify common orthographic
+//! variants (繁體/简体) so they index as the same token, e.g. `檔案` and `档案`.
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+/// (traditional, simplified) character pairs. Unordered: lookups go through the
+/// `HashMap` built by `forward_table`/`reverse_table`, not a binary search.
+const TRADITIONAL_SIMPLIFIED_PAIRS: &[(char, char)] = &[
+    ('專', '专'),
+    ('東', '东'),
+    ('絲', '丝'),
+    ('両', '两'),
+    ('麗', '丽'),
+    ('舉', '举'),
+    ('麼', '么'),
+    ('義', '义'),
+    ('烏', '乌'),
+    ('樂', '乐'),
+    ('習', '习'),
+    ('鄉', '乡'),
+    ('書', '书'),
+    ('買', '买'),
+    ('亂', '乱'),
+    ('爭', '争'),
+    ('於', '于'),
+    ('虧', '亏'),
+    ('雲', '云'),
+    ('亙', '亘'),
+    ('亞', '亚'),
+    ('產', '产'),
+    ('畝', '亩'),
+    ('親', '亲'),
+    ('佇', '伫'),
+    ('體', '体'),
+    ('餘', '余'),
+    ('來', '来'),
+    ('侖', '仑'),
+    ('個', '个'),
+    ('價', '价'),
+    ('眾', '众'),
+    ('優', '优'),
+    ('夥', '伙'),
+    ('會', '会'),
+    ('傴', '伛'),
+    ('傘', '伞'),
+    ('偉', '伟'),
+    ('傳', '传'),
+    ('傷', '伤'),
+    ('偽', '伪'),
+    ('們', '们'),
+    ('儀', '仪'),
+    ('億', '亿'),
+    ('儈', '侩'),
+    ('儂', '侬'),
+    ('儲', '储'),
+    ('儷', '俪'),
+    ('儼', '俨'),
+    ('兒', '儿'),
+    ('兗', '兖'),
+    ('黨', '党'),
+    ('內', '内'),
+    ('岡', '冈'),
+    ('冊', '册'),
+    ('寫', '写'),
+    ('軍', '军'),
+    ('農', '农'),
+    ('馮', '冯'),
+    ('衝', '冲'),
+    ('決', '决'),
+    ('況', '况'),
+    ('凍', '冻'),
+    ('淨', '净'),
+    ('涼', '凉'),
+    ('凱', '凯'),
+    ('幾', '几'),
+    ('鳳', '凤'),
+    ('鳧', '凫'),
+    ('憑', '凭'),
+    ('擊', '击'),
+    ('鑿', '凿'),
+    ('芻', '刍'),
+    ('劃', '划'),
+    ('劉', '刘'),
+    ('則', '则'),
+    ('剛', '刚'),
+    ('創', '创'),
+    ('刪', '删'),
+    ('別', '别'),
+    ('剎', '刹'),
+    ('劑', '剂'),
+    ('剝', '剥'),
+    ('飼', '饲'),
+    ('劍', '剑'),
+    ('勁', '劲'),
+    ('動', '动'),
+    ('務', '务'),
+    ('勝', '胜'),
+    ('勞', '劳'),
+    ('勢', '势'),
+    ('勱', '劢'),
+    ('勵', '励'),
+    ('勸', '劝'),
+    ('辦', '办'),
+    ('幣', '币'),
+    ('匭', '匦'),
+    ('匯', '汇'),
+    ('醫', '医'),
+    ('華', '华'),
+    ('協', '协'),
+    ('單', '单'),
+    ('賣', '卖'),
+    ('卻', '却'),
+    ('廠', '厂'),
+    ('曆', '历'),
+    ('厲', '厉'),
+    ('參', '参'),
+    ('雙', '双'),
+    ('發', '发'),
+    ('變', '变'),
+    ('敘', '叙'),
+    ('疊', '叠'),
+    ('聖', '圣'),
+    ('對', '对'),
+    ('臺', '台'),
+    ('裝', '装'),
+    ('馱', '驮'),
+    ('馳', '驰'),
+    ('區', '区'),
+    ('醜', '丑'),
+    ('壓', '压'),
+    ('厭', '厌'),
+    ('礙', '碍'),
+    ('電', '电'),
+    ('靈', '灵'),
+    ('響', '响'),
+    ('訁', '讠'),
+    ('語', '语'),
+    ('說', '说'),
+    ('讀', '读'),
+    ('誰', '谁'),
+    ('課', '课'),
+    ('調', '调'),
+    ('談', '谈'),
+    ('請', '请'),
+    ('諸', '诸'),
+    ('諾', '诺'),
+    ('謝', '谢'),
+    ('證', '证'),
+    ('識', '识'),
+    ('譯', '译'),
+    ('議', '议'),
+    ('護', '护'),
+    ('讓', '让'),
+    ('變', '变'),
+    ('贊', '赞'),
+    ('齊', '齐'),
+    ('慶', '庆'),
+    ('窮', '穷'),
+    ('寧', '宁'),
+    ('開', '开'),
+    ('關', '关'),
+    ('門', '门'),
+    ('閉', '闭'),
+    ('問', '问'),
+    ('間', '间'),
+    ('閒', '闲'),
+    ('閘', '闸'),
+    ('閱', '阅'),
+    ('閣', '阁'),
+    ('閩', '闽'),
+    ('闆', '板'),
+    ('闊', '阔'),
+    ('隊', '队'),
+    ('陽', '阳'),
+    ('陰', '阴'),
+    ('陳', '陈'),
+    ('險', '险'),
+    ('階', '阶'),
+    ('際', '际'),
+    ('隨', '随'),
+    ('難', '难'),
+    ('雞', '鸡'),
+    ('雜', '杂'),
+    ('頭', '头'),
+    ('頁', '页'),
+    ('題', '题'),
+    ('額', '额'),
+    ('顏', '颜'),
+    ('願', '愿'),
+    ('風', '风'),
+    ('飛', '飞'),
+    ('飯', '饭'),
+    ('飲', '饮'),
+    ('館', '馆'),
+    ('驗', '验'),
+    ('馬', '马'),
+    ('魚', '鱼'),
+    ('魯', '鲁'),
+    ('鮮', '鲜'),
+    ('鳥', '鸟'),
+    ('鴨', '鸭'),
+    ('鹼', '碱'),
+    ('麥', '麦'),
+    ('麵', '面'),
+    ('黃', '黄'),
+    ('點', '点'),
+    ('齒', '齿'),
+    ('龍', '龙'),
+    ('龜', '龟'),
+    ('學', '学'),
+    ('號', '号'),
+    ('國', '国'),
+    ('圖', '图'),
+    ('團', '团'),
+    ('園', '园'),
+    ('圍', '围'),
+    ('壇', '坛'),
+    ('場', '场'),
+    ('報', '报'),
+    ('塊', '块'),
+    ('聲', '声'),
+    ('處', '处'),
+    ('備', '备'),
+    ('複', '复'),
+    ('夾', '夹'),
+    ('奪', '夺'),
+    ('奮', '奋'),
+    ('嬰', '婴'),
+    ('嬌', '娇'),
+    ('媽', '妈'),
+    ('嫵', '妩'),
+    ('嫗', '妪'),
+    ('嬈', '娆'),
+    ('嬪', '嫔'),
+    ('孫', '孙'),
+    ('寶', '宝'),
+    ('實', '实'),
+    ('審', '审'),
+    ('導', '导'),
+    ('層', '层'),
+    ('屬', '属'),
+    ('帳', '帐'),
+    ('幫', '帮'),
+    ('庫', '库'),
+    ('應', '应'),
+    ('廣', '广'),
+    ('廳', '厅'),
+    ('異', '异'),
+    ('廢', '废'),
+    ('強', '强'),
+    ('彈', '弹'),
+    ('歸', '归'),
+    ('當', '当'),
+    ('錄', '录'),
+    ('從', '从'),
+    ('倫', '伦'),
+    ('偵', '侦'),
+    ('側', '侧'),
+    ('僅', '仅'),
+    ('侶', '侣'),
+    ('檔', '档'),
+    ('簡', '简'),
+];
+
+/// (traditional -> simplified) lookup, built once from `TRADITIONAL_SIMPLIFIED_PAIRS`.
+fn forward_table() -> &'static HashMap<char, char> {
+    static TABLE: OnceLock<HashMap<char, char>> = OnceLock::new();
+    TABLE.get_or_init(|| TRADITIONAL_SIMPLIFIED_PAIRS.iter().copied().collect())
+}
+
+/// (simplified -> traditional) lookup, built once from `TRADITIONAL_SIMPLIFIED_PAIRS`.
+fn reverse_table() -> &'static HashMap<char, char> {
+    static TABLE: OnceLock<HashMap<char, char>> = OnceLock::new();
+    TABLE.get_or_init(|| {
+        TRADITIONAL_SIMPLIFIED_PAIRS
+            .iter()
+            .map(|&(traditional, simplified)| (simplified, traditional))
+            .collect()
+    })
+}
+
+fn convert(text: &str, forward: bool) -> String {
+    let table = if forward {
+        forward_table()
+    } else {
+        reverse_table()
+    };
+    text.chars()
+        .map(|c| table.get(&c).copied().unwrap_or(c))
+        .collect()
+}
+
+/// Fold traditional Han characters to their simplified form, leaving everything else untouched.
+pub fn to_simplified(text: &str) -> String {
+    convert(text, true)
+}
+
+/// Fold simplified Han characters to their traditional form, leaving everything else untouched.
+pub fn to_traditional(text: &str) -> String {
+    convert(text, false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_simplified() {
+        assert_eq!(to_simplified("檔案"), "档案");
+        assert_eq!(to_simplified("繁體"), "繁体");
+        // Non-Han and already-simplified characters pass through untouched.
+        assert_eq!(to_simplified("abc 简体"), "abc 简体");
+    }
+}
@@ -0,0 +1,3 @@
+pub mod classifier;
+mod han_conversion;
+pub mod tokenizer;
@@ -6,12 +6,14 @@ use std::{
     time::Instant,
 };
 
+use jieba_rs::Jieba;
 use log::debug;
 use serde::{Deserialize, Serialize};
-use tantivy_stemmers::algorithms::english_porter as stemmer;
+use tantivy_stemmers::algorithms;
 use unicode_normalization::UnicodeNormalization;
 use unicode_segmentation::UnicodeSegmentation;
 
+use crate::han_conversion;
 use crate::stopwords::{CHINESE_NLTK_SINGLE, CJK_LUCENE, ENGLISH_LUCENE, ENGLISH_NLTK};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -26,6 +28,10 @@ pub enum Normalization {
     NFKD,
     /// Compatibility Decomposition, followed by Canonical Composition
     NFKC,
+    /// Fold traditional Han characters (繁體) to their simplified form (简体), e.g. `檔案` -> `档案`.
+    SimplifiedChinese,
+    /// Fold simplified Han characters (简体) to their traditional form (繁體), e.g. `档案` -> `檔案`.
+    TraditionalChinese,
     /// No normalization
     None,
 }
@@ -38,25 +44,86 @@ impl Normalization {
             Normalization::NFC => text.nfc().collect(),
             Normalization::NFKD => text.nfkd().collect(),
             Normalization::NFKC => text.nfkc().collect(),
+            Normalization::SimplifiedChinese => han_conversion::to_simplified(text),
+            Normalization::TraditionalChinese => han_conversion::to_traditional(text),
             Normalization::None => text.to_string(),
         }
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+/// Language for the `Stemmer::Snowball` variant.
+/// Ref: https://snowballstem.org/algorithms/
+pub enum Language {
+    #[default]
+    English,
+    French,
+    German,
+    Spanish,
+    Italian,
+    Portuguese,
+    Dutch,
+    Swedish,
+    Russian,
+}
+
+#[derive(Debug, Clone, Serialize)]
 /// Stemmer method.
 pub enum Stemmer {
     /// https://snowballstem.org/algorithms/
-    Snowball,
+    Snowball(Language),
     /// No stemmer
     None,
 }
 
+impl<'de> Deserialize<'de> for Stemmer {
+    /// Accepts the current `{"Snowball": <Language>}` / `"None"` representation, as well as
+    /// the bare `"Snowball"` / `"None"` strings dumped by tokenizers from before `Snowball`
+    /// carried a `Language` payload, defaulting those to `Language::English`.
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        enum Tagged {
+            Snowball(Language),
+            None,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Tagged(Tagged),
+            Legacy(String),
+        }
+
+        match Repr::deserialize(deserializer)? {
+            Repr::Tagged(Tagged::Snowball(language)) => Ok(Stemmer::Snowball(language)),
+            Repr::Tagged(Tagged::None) => Ok(Stemmer::None),
+            Repr::Legacy(s) if s == "Snowball" => Ok(Stemmer::Snowball(Language::default())),
+            Repr::Legacy(s) if s == "None" => Ok(Stemmer::None),
+            Repr::Legacy(other) => Err(serde::de::Error::custom(format!(
+                "unknown Stemmer variant: {other}"
+            ))),
+        }
+    }
+}
+
 impl Stemmer {
     /// Stem the text.
     pub fn stem<'a>(&self, text: &'a str) -> Cow<'a, str> {
         match self {
-            Stemmer::Snowball => stemmer(text),
+            Stemmer::Snowball(language) => match language {
+                Language::English => algorithms::english_porter(text),
+                Language::French => algorithms::french(text),
+                Language::German => algorithms::german(text),
+                Language::Spanish => algorithms::spanish(text),
+                Language::Italian => algorithms::italian(text),
+                Language::Portuguese => algorithms::portuguese(text),
+                Language::Dutch => algorithms::dutch(text),
+                Language::Swedish => algorithms::swedish(text),
+                Language::Russian => algorithms::russian(text),
+            },
             Stemmer::None => Cow::Borrowed(text),
         }
     }
@@ -77,6 +144,60 @@ pub fn english_possessive_filter(text: &str) -> Option<String> {
     }
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+/// A single step of the token transform pipeline, applied in the order they
+/// appear in `Tokenizer::pipeline`.
+pub enum Filter {
+    /// Lowercase the token.
+    Lowercase,
+    /// Strip a trailing English possessive (`John's` -> `John`).
+    PossessiveStrip,
+    /// Drop the token if it is in `Tokenizer::stopwords`.
+    StopWords,
+    /// Stem the token with `Tokenizer::stemmer`.
+    Stem,
+    /// Normalize the token with `Tokenizer::norm`.
+    Normalize,
+    /// Drop the token if its byte length exceeds the limit. Guards against giant
+    /// non-space CJK/URL runs blowing up the vocabulary.
+    RemoveLong(usize),
+    /// Decompose accented Latin letters to their base ASCII form (e.g. `café` -> `cafe`).
+    AsciiFold,
+    /// Drop the token if it contains no alphanumeric character.
+    AlphaNumOnly,
+}
+
+/// Decompose accented Latin letters to their base ASCII form.
+fn ascii_fold(text: &str) -> String {
+    text.nfd().filter(|c| !('\u{0300}'..='\u{036f}').contains(c)).collect()
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+/// A reserved token that bypasses the standard transform pipeline, e.g. a product code,
+/// handle, or markup-like token (`C++`, `@user`, `__LABEL__`) that lowercasing/stemming
+/// would otherwise destroy.
+pub struct AddedToken {
+    /// The raw text of the token.
+    pub content: String,
+    /// If `true`, only match `content` as a whole word; otherwise also match it as a
+    /// substring at a word boundary.
+    pub single_word: bool,
+    /// If `true`, still run the matched span through the standard pipeline instead of
+    /// emitting it verbatim.
+    pub normalized: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+/// Word segmentation strategy.
+pub enum Segmenter {
+    /// Split on Unicode word boundaries. Works for most scripts but does not
+    /// segment Han script into real words.
+    Unicode,
+    /// Segment the whole text with `jieba_rs`; non-CJK spans are split by jieba's
+    /// own rules, not by Unicode word boundaries.
+    Jieba,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 /// Tokenizer for text keyword match.
 pub struct Tokenizer {
@@ -88,8 +209,26 @@ pub struct Tokenizer {
     pub norm: Normalization,
     /// The stemmer method.
     pub stemmer: Stemmer,
+    /// The word segmentation strategy. Private: mutate it with `set_segmenter` so the
+    /// `jieba_rs` segmenter it needs is prepared; `load`/`loads` rebuild it after
+    /// deserialization.
+    segmenter: Segmenter,
+    /// The ordered transform pipeline applied to every word in `get_token`.
+    pub pipeline: Vec<Filter>,
+    /// Reserved tokens matched against the raw input before the standard pipeline runs.
+    pub added_tokens: Vec<AddedToken>,
     table: HashMap<String, u32>,
     counter: Vec<u32>,
+    /// Number of fitted documents each term appears in at least once, aligned by id with `counter`.
+    doc_freq: Vec<u32>,
+    /// Total number of fitted documents, i.e. `N` in the BM25 formula.
+    doc_count: u32,
+    /// Average document length (in surviving tokens) across the fitted documents.
+    avg_doc_len: f32,
+    /// Lazily constructed `jieba_rs` segmenter, rebuilt after `load`/`loads`
+    /// since `Jieba` itself is not serializable.
+    #[serde(skip)]
+    jieba: Option<Jieba>,
 }
 
 impl Default for Tokenizer {
@@ -105,49 +244,241 @@ impl Default for Tokenizer {
             .flat_map(|slice| slice.iter().map(|x| x.to_string()))
             .collect(),
             norm: Normalization::None,
-            stemmer: Stemmer::Snowball,
+            stemmer: Stemmer::Snowball(Language::default()),
+            segmenter: Segmenter::Unicode,
+            pipeline: vec![
+                Filter::Lowercase,
+                Filter::PossessiveStrip,
+                Filter::StopWords,
+                Filter::Stem,
+                Filter::Normalize,
+            ],
+            added_tokens: Vec::new(),
             table: HashMap::new(),
             counter: Vec::new(),
+            doc_freq: Vec::new(),
+            doc_count: 0,
+            avg_doc_len: 0.0,
             min_freq: 5,
+            jieba: None,
         }
     }
 }
 
 impl Tokenizer {
-    fn get_token(&self, content: &str) -> Vec<String> {
-        let lowercase = content.to_lowercase();
-        let mut tokens = Vec::new();
-        for word in lowercase.unicode_words() {
-            let word = match english_possessive_filter(word) {
-                Some(w) => w,
-                None => word.to_string(),
-            };
-            if self.stopwords.contains(&word) {
-                continue;
+    /// The current word segmentation strategy.
+    pub fn segmenter(&self) -> Segmenter {
+        self.segmenter
+    }
+
+    /// Switch the segmentation strategy, eagerly preparing any resources it needs.
+    pub fn set_segmenter(&mut self, segmenter: Segmenter) {
+        self.jieba = match segmenter {
+            Segmenter::Jieba => Some(Jieba::new()),
+            Segmenter::Unicode => None,
+        };
+        self.segmenter = segmenter;
+    }
+
+    /// Rebuild any resources that are not serialized, such as the `jieba_rs` segmenter.
+    fn rebuild_transient_state(&mut self) {
+        if matches!(self.segmenter, Segmenter::Jieba) && self.jieba.is_none() {
+            self.jieba = Some(Jieba::new());
+        }
+    }
+
+    /// Split `content` into words, paired with their byte offset in `content`.
+    fn words_with_offsets<'a>(&self, content: &'a str) -> Vec<(&'a str, usize)> {
+        match self.segmenter {
+            Segmenter::Unicode => content
+                .unicode_word_indices()
+                .map(|(offset, word)| (word, offset))
+                .collect(),
+            Segmenter::Jieba => {
+                let base = content.as_ptr() as usize;
+                self.jieba
+                    .as_ref()
+                    .expect("jieba segmenter not initialized, call `set_segmenter` first")
+                    .cut(content, false)
+                    .into_iter()
+                    .filter(|word| word.chars().any(|c| c.is_alphanumeric()))
+                    .map(|word| (word, word.as_ptr() as usize - base))
+                    .collect()
+            }
+        }
+    }
+
+    /// Apply a single `Filter` to `token`, returning `None` if the filter drops it.
+    fn apply_filter<'a>(&self, filter: &Filter, token: Cow<'a, str>) -> Option<Cow<'a, str>> {
+        match filter {
+            Filter::Lowercase => Some(Cow::Owned(token.to_lowercase())),
+            Filter::PossessiveStrip => match english_possessive_filter(&token) {
+                Some(w) => Some(Cow::Owned(w)),
+                None => Some(token),
+            },
+            Filter::StopWords => (!self.stopwords.contains(token.as_ref())).then_some(token),
+            Filter::Stem => Some(Cow::Owned(self.stemmer.stem(&token).into_owned())),
+            Filter::Normalize => Some(Cow::Owned(self.norm.normalize(&token))),
+            Filter::RemoveLong(limit) => (token.len() <= *limit).then_some(token),
+            Filter::AsciiFold => Some(Cow::Owned(ascii_fold(&token))),
+            Filter::AlphaNumOnly => token
+                .chars()
+                .any(|c| c.is_alphanumeric())
+                .then_some(token),
+        }
+    }
+
+    /// Run the configured `pipeline` over `word`, returning `None` if any filter drops it.
+    fn apply_pipeline(&self, word: &str) -> Option<String> {
+        let mut current = Cow::Borrowed(word);
+        for filter in &self.pipeline {
+            current = self.apply_filter(filter, current)?;
+        }
+        (!current.is_empty()).then(|| current.into_owned())
+    }
+
+    /// Find non-overlapping spans of `content` that match an `added_tokens` entry,
+    /// scanning left to right and preferring the first configured token that matches
+    /// at each position.
+    fn find_added_token_spans(&self, content: &str) -> Vec<(std::ops::Range<usize>, &AddedToken)> {
+        let mut spans = Vec::new();
+        if self.added_tokens.is_empty() {
+            return spans;
+        }
+        let is_alphanumeric_boundary = |index: usize, back: bool| -> bool {
+            if back {
+                index == 0 || !content[..index].chars().next_back().is_some_and(char::is_alphanumeric)
+            } else {
+                index == content.len() || !content[index..].chars().next().is_some_and(char::is_alphanumeric)
             }
-            let token = self.norm.normalize(self.stemmer.stem(&word).as_ref());
-            if token.is_empty() {
+        };
+        let mut cursor = 0;
+        while cursor < content.len() {
+            if !content.is_char_boundary(cursor) {
+                cursor += 1;
                 continue;
             }
-            tokens.push(token);
+            let rest = &content[cursor..];
+            let found = self.added_tokens.iter().find(|added| {
+                // An empty `content` would match every position without advancing the
+                // cursor, hanging the loop below; treat it as never matching.
+                if added.content.is_empty() || !rest.starts_with(added.content.as_str()) {
+                    return false;
+                }
+                let end = cursor + added.content.len();
+                let left = is_alphanumeric_boundary(cursor, true);
+                let right = is_alphanumeric_boundary(end, false);
+                if added.single_word {
+                    left && right
+                } else {
+                    left || right
+                }
+            });
+            match found {
+                Some(added) => {
+                    let end = cursor + added.content.len();
+                    spans.push((cursor..end, added));
+                    cursor = end;
+                }
+                None => cursor += 1,
+            }
         }
+        spans
+    }
 
+    /// The form an `AddedToken` takes in the `table`/token stream: the raw content
+    /// verbatim, or its content run through the standard pipeline when `normalized`.
+    fn added_token_form(&self, added: &AddedToken) -> Option<String> {
+        if added.normalized {
+            self.apply_pipeline(&added.content)
+        } else {
+            Some(added.content.clone())
+        }
+    }
+
+    /// Run the transform pipeline on each word, keeping the byte range of the *source*
+    /// word (not the transformed token) in `content`. `added_tokens` are matched against
+    /// the raw input first and bypass the pipeline unless `normalized` is set.
+    fn get_token_with_offsets(&self, content: &str) -> Vec<(String, std::ops::Range<usize>)> {
+        let mut tokens = Vec::new();
+        let mut cursor = 0;
+        for (range, added) in self.find_added_token_spans(content) {
+            if cursor < range.start {
+                self.push_segment_tokens(&content[cursor..range.start], cursor, &mut tokens);
+            }
+            if let Some(token) = self.added_token_form(added) {
+                tokens.push((token, range.clone()));
+            }
+            cursor = range.end;
+        }
+        if cursor < content.len() {
+            self.push_segment_tokens(&content[cursor..], cursor, &mut tokens);
+        }
         tokens
     }
 
+    /// Tokenize a plain segment (no `added_tokens` matches) of `content`, starting at
+    /// byte offset `base`, appending the results to `tokens`.
+    fn push_segment_tokens(
+        &self,
+        segment: &str,
+        base: usize,
+        tokens: &mut Vec<(String, std::ops::Range<usize>)>,
+    ) {
+        for (word, offset) in self.words_with_offsets(segment) {
+            let range = base + offset..base + offset + word.len();
+            if let Some(token) = self.apply_pipeline(word) {
+                tokens.push((token, range));
+            }
+        }
+    }
+
+    pub(crate) fn get_token(&self, content: &str) -> Vec<String> {
+        self.get_token_with_offsets(content)
+            .into_iter()
+            .map(|(token, _)| token)
+            .collect()
+    }
+
     /// Fit the tokenizer with the contents. Re-call this function will update the tokenizer.
     pub fn fit(&mut self, contents: &[String]) {
         let instant = Instant::now();
         let exist_token = self.table.len();
+        let mut total_len = 0u64;
         for content in contents {
             let tokens = self.get_token(content);
+            total_len += tokens.len() as u64;
+            let mut seen = HashSet::new();
             for token in tokens {
                 let length = self.table.len();
-                let entry = self.table.entry(token).or_insert(length as u32);
-                if *entry == self.counter.len() as u32 {
+                let id = *self.table.entry(token).or_insert(length as u32);
+                if id as usize == self.counter.len() {
                     self.counter.push(0);
+                    self.doc_freq.push(0);
+                }
+                self.counter[id as usize] += 1;
+                if seen.insert(id) {
+                    self.doc_freq[id as usize] += 1;
                 }
-                self.counter[*entry as usize] += 1;
+            }
+        }
+        let new_doc_count = self.doc_count + contents.len() as u32;
+        if new_doc_count > 0 {
+            self.avg_doc_len = ((self.avg_doc_len as f64 * self.doc_count as f64 + total_len as f64)
+                / new_doc_count as f64) as f32;
+        }
+        self.doc_count = new_doc_count;
+        // Added tokens must be present in the vocabulary even if `min_freq` would
+        // otherwise exclude them, or if they never occurred in `contents`.
+        for added in self.added_tokens.clone() {
+            let Some(token) = self.added_token_form(&added) else {
+                continue;
+            };
+            let length = self.table.len();
+            let id = *self.table.entry(token).or_insert(length as u32);
+            if id as usize == self.counter.len() {
+                self.counter.push(0);
+                self.doc_freq.push(0);
             }
         }
         debug!(
@@ -170,12 +501,38 @@ impl Tokenizer {
         ids
     }
 
-    /// This will trim the `table` according to the `min_freq` and clean the `counter`.
+    /// Tokenize the content and return, for each surviving token, its id, its ordinal
+    /// position in the stream, and the byte range of the source word in `content`.
+    ///
+    /// This is the shape a full-text index needs to build positional postings and to
+    /// highlight matches without re-tokenizing.
+    pub fn tokenize_with_positions(&self, content: &str) -> Vec<(u32, usize, std::ops::Range<usize>)> {
+        let tokens = self.get_token_with_offsets(content);
+        let mut result = Vec::with_capacity(tokens.len());
+        let mut position = 0usize;
+        for (token, range) in tokens {
+            if let Some(&id) = self.table.get(&token) {
+                result.push((id, position, range));
+                position += 1;
+            }
+        }
+        result
+    }
+
+    /// This will trim the `table` according to the `min_freq`, clean the `counter`, and
+    /// remap `doc_freq` onto the surviving ids so `bm25_score` stays usable right after.
     pub fn trim(&mut self) {
+        let protected: HashSet<String> = self
+            .added_tokens
+            .iter()
+            .filter_map(|added| self.added_token_form(added))
+            .collect();
         let mut selected = HashMap::new();
+        let mut doc_freq = Vec::new();
         for (token, &id) in self.table.iter() {
-            if self.counter[id as usize] >= self.min_freq {
+            if self.counter[id as usize] >= self.min_freq || protected.contains(token) {
                 selected.insert(token.clone(), selected.len() as u32);
+                doc_freq.push(self.doc_freq[id as usize]);
             }
         }
         debug!(
@@ -185,6 +542,43 @@ impl Tokenizer {
         );
         self.table = selected;
         self.counter.clear();
+        self.doc_freq = doc_freq;
+    }
+
+    /// Score `doc` against `query` with BM25, using the document frequencies and average
+    /// document length recorded by `fit`. Both `query` and `doc` are tokenized with
+    /// `get_token` so scoring respects the configured stopwords/stemmer/normalization.
+    pub fn bm25_score(&self, query: &str, doc: &str) -> f32 {
+        const K1: f32 = 1.2;
+        const B: f32 = 0.75;
+
+        if self.doc_count == 0 || self.avg_doc_len == 0.0 {
+            return 0.0;
+        }
+
+        let doc_tokens = self.get_token(doc);
+        let doc_len = doc_tokens.len() as f32;
+        let mut term_freq: HashMap<String, u32> = HashMap::new();
+        for token in doc_tokens {
+            *term_freq.entry(token).or_insert(0) += 1;
+        }
+
+        let n = self.doc_count as f32;
+        let mut score = 0.0;
+        for term in self.get_token(query) {
+            let Some(&id) = self.table.get(&term) else {
+                continue;
+            };
+            let f = *term_freq.get(&term).unwrap_or(&0) as f32;
+            if f == 0.0 {
+                continue;
+            }
+            let df = self.doc_freq[id as usize] as f32;
+            let idf = ((n - df + 0.5) / (df + 0.5) + 1.0).ln();
+            let denom = f + K1 * (1.0 - B + B * doc_len / self.avg_doc_len);
+            score += idf * (f * (K1 + 1.0)) / denom;
+        }
+        score
     }
 
     /// Serialize the tokenizer into a JSON string.
@@ -199,13 +593,18 @@ impl Tokenizer {
 
     /// Deserialize the tokenizer from a JSON string.
     pub fn loads(data: &str) -> Self {
-        serde_json::from_str(data).unwrap()
+        let mut tokenizer: Self = serde_json::from_str(data).unwrap();
+        tokenizer.rebuild_transient_state();
+        tokenizer
     }
 
     /// Deserialize the tokenizer from a JSON file.
     pub fn load(path: &impl AsRef<std::path::Path>) -> Self {
-        serde_json::from_slice(&std::fs::read(path).expect("failed to read"))
-            .expect("failed to deserialize")
+        let mut tokenizer: Self =
+            serde_json::from_slice(&std::fs::read(path).expect("failed to read"))
+                .expect("failed to deserialize");
+        tokenizer.rebuild_transient_state();
+        tokenizer
     }
 
     /// Get the total token number.
@@ -216,7 +615,7 @@ impl Tokenizer {
 
 #[cfg(test)]
 mod tests {
-    use crate::tokenizer::english_possessive_filter;
+    use super::*;
 
     #[test]
     fn test_english_possessive_filter() {
@@ -237,4 +636,97 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_bm25_score_after_trim() {
+        let mut tokenizer = Tokenizer {
+            min_freq: 1,
+            ..Default::default()
+        };
+        let contents = vec![
+            "the cat sat on the mat".to_string(),
+            "the dog played in the yard".to_string(),
+            "cats and dogs are friends".to_string(),
+        ];
+        tokenizer.fit(&contents);
+        tokenizer.trim();
+
+        // Must not panic indexing `doc_freq` with ids remapped by `trim`.
+        let cat_score = tokenizer.bm25_score("cat", "the cat sat on the mat");
+        let dog_score = tokenizer.bm25_score("cat", "the dog played in the yard");
+        assert!(cat_score > 0.0);
+        assert!(cat_score > dog_score);
+    }
+
+    #[test]
+    fn test_tokenize_with_positions() {
+        let mut tokenizer = Tokenizer {
+            min_freq: 1,
+            ..Default::default()
+        };
+        let content = "Dogs love bones".to_string();
+        tokenizer.fit(std::slice::from_ref(&content));
+
+        let positions = tokenizer.tokenize_with_positions(&content);
+        let ids = tokenizer.tokenize(&content);
+        assert_eq!(positions.len(), ids.len());
+        assert_eq!(positions.len(), 3);
+
+        for (i, (id, position, _)) in positions.iter().enumerate() {
+            assert_eq!(*id, ids[i]);
+            assert_eq!(*position, i);
+        }
+        // "bones" is the 3rd surviving token and starts after "Dogs love ".
+        let (_, position, range) = &positions[2];
+        assert_eq!(*position, 2);
+        assert_eq!(&content[range.clone()], "bones");
+    }
+
+    #[test]
+    fn test_added_token_survives_trim() {
+        let mut tokenizer = Tokenizer {
+            min_freq: 5,
+            added_tokens: vec![AddedToken {
+                content: "C++".to_string(),
+                single_word: true,
+                normalized: false,
+            }],
+            ..Default::default()
+        };
+        // "C++" only occurs once, well under `min_freq`, but must survive `trim`.
+        tokenizer.fit(&["I write C++ for a living".to_string()]);
+        tokenizer.trim();
+
+        let ids = tokenizer.tokenize("C++");
+        assert_eq!(ids.len(), 1);
+    }
+
+    #[test]
+    fn test_find_added_token_spans_ignores_empty_content() {
+        let tokenizer = Tokenizer {
+            added_tokens: vec![AddedToken {
+                content: String::new(),
+                single_word: true,
+                normalized: false,
+            }],
+            ..Default::default()
+        };
+        // An empty `content` must never match, or the scan would never advance.
+        let spans = tokenizer.find_added_token_spans("hello world");
+        assert!(spans.is_empty());
+    }
+
+    #[test]
+    fn test_stemmer_deserializes_legacy_unit_variant() {
+        // Tokenizers dumped before `Snowball` gained a `Language` payload serialized it as
+        // the bare string `"Snowball"`; loading one of those dumps must still succeed.
+        let legacy: Stemmer = serde_json::from_str("\"Snowball\"").unwrap();
+        assert!(matches!(legacy, Stemmer::Snowball(Language::English)));
+
+        let legacy_none: Stemmer = serde_json::from_str("\"None\"").unwrap();
+        assert!(matches!(legacy_none, Stemmer::None));
+
+        let current: Stemmer = serde_json::from_str("{\"Snowball\":\"French\"}").unwrap();
+        assert!(matches!(current, Stemmer::Snowball(Language::French)));
+    }
 }